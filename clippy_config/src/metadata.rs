@@ -1,23 +1,35 @@
+use serde::Serialize;
 use std::fmt;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ClippyConfiguration {
     pub name: String,
     config_type: &'static str,
     pub default: String,
+    #[serde(serialize_with = "serialize_lints")]
     pub lints: Vec<String>,
     pub doc: String,
-    #[allow(dead_code)]
     pub deprecation_reason: Option<&'static str>,
 }
 
+/// Serializes `lints` as the cleaned lint slugs (first whitespace-delimited token of each
+/// entry), matching what [`ClippyConfiguration::to_markdown_paragraph`] already extracts, so
+/// the resulting JSON keys join cleanly with `lints.json`.
+fn serialize_lints<S: serde::Serializer>(lints: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(lints.iter().map(|lint| lint.split_whitespace().next().unwrap_or_default()))
+}
+
 impl fmt::Display for ClippyConfiguration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(
-            f,
-            "* `{}`: `{}`(defaults to `{}`): {}",
-            self.name, self.config_type, self.default, self.doc
-        )
+        if let Some(reason) = self.deprecation_reason {
+            writeln!(f, "* `{}`: ⚠️ Deprecated: {}", self.name, reason)
+        } else {
+            writeln!(
+                f,
+                "* `{}`: `{}`(defaults to `{}`): {}",
+                self.name, self.config_type, self.default, self.doc
+            )
+        }
     }
 }
 
@@ -45,15 +57,13 @@ impl ClippyConfiguration {
     #[cfg(feature = "internal")]
     pub fn to_markdown_paragraph(&self) -> String {
         format!(
-            "## `{}`\n{}\n\n**Default Value:** `{}` (`{}`)\n\n---\n**Affected lints:**\n{}\n\n",
+            "## `{}`\n{}\n\n{}\n\n---\n**Affected lints:**\n{}\n\n",
             self.name,
-            self.doc
-                .lines()
-                .map(|line| line.strip_prefix("    ").unwrap_or(line))
-                .collect::<Vec<_>>()
-                .join("\n"),
-            self.default,
-            self.config_type,
+            self.doc,
+            match self.deprecation_reason {
+                Some(reason) => format!("**⚠️ Deprecated:** {reason}"),
+                None => format!("**Default Value:** `{}` (`{}`)", self.default, self.config_type),
+            },
             self.lints
                 .iter()
                 .map(|name| name.to_string().split_whitespace().next().unwrap().to_string())
@@ -70,43 +80,250 @@ impl ClippyConfiguration {
     }
 }
 
+/// Splits the collected configuration metadata into active and deprecated entries, so the
+/// generated `lint_configuration.html` docs can render deprecated options in a separate
+/// section instead of interleaving them with options that are still in effect.
+#[cfg(feature = "internal")]
+pub fn partition_deprecated(configs: Vec<ClippyConfiguration>) -> (Vec<ClippyConfiguration>, Vec<ClippyConfiguration>) {
+    configs.into_iter().partition(|config| config.deprecation_reason.is_none())
+}
+
+/// Serializes the collected configuration metadata as the JSON document for a
+/// `clippy_config.json` alongside `lints.json`, so gh-pages can render an interactive,
+/// filterable configuration browser and cross-link options to the lints they affect.
+/// Only the JSON text is returned; this function does not write the file.
+#[cfg(feature = "internal")]
+pub fn configs_to_json(configs: &[ClippyConfiguration]) -> String {
+    serde_json::to_string_pretty(configs).expect("failed to serialize configuration metadata")
+}
+
+/// Maps a config field's Rust type string (e.g. `Vec<String>`, `u64`, `Option<String>`) to the
+/// corresponding JSON Schema fragment. Unknown or unsupported types degrade gracefully to an
+/// untyped schema entry rather than failing the whole generation; the caller is still expected
+/// to attach a `description` to it.
+fn config_type_to_json_schema(config_type: &str) -> serde_json::Value {
+    let config_type = config_type.trim();
+
+    if let Some(inner) = config_type.strip_prefix("Option<").and_then(|rest| rest.strip_suffix('>')) {
+        let mut schema = config_type_to_json_schema(inner);
+        // `nullable` is an OpenAPI keyword, not part of JSON Schema draft-07 (our declared
+        // `$schema`), so a draft-07 validator would silently ignore it and still reject
+        // `null`. Express the null option the way draft-07 actually supports: a `type` array.
+        if let Some(schema) = schema.as_object_mut()
+            && let Some(serde_json::Value::String(ty)) = schema.remove("type")
+        {
+            schema.insert("type".to_string(), serde_json::json!([ty, "null"]));
+        }
+        return schema;
+    }
+
+    if let Some(inner) = config_type.strip_prefix("Vec<").and_then(|rest| rest.strip_suffix('>')) {
+        return serde_json::json!({
+            "type": "array",
+            "items": config_type_to_json_schema(inner),
+        });
+    }
+
+    match config_type {
+        "bool" => serde_json::json!({ "type": "boolean" }),
+        "u8" => serde_json::json!({ "type": "integer", "minimum": u8::MIN, "maximum": u8::MAX }),
+        "u16" => serde_json::json!({ "type": "integer", "minimum": u16::MIN, "maximum": u16::MAX }),
+        "u32" => serde_json::json!({ "type": "integer", "minimum": u32::MIN, "maximum": u32::MAX }),
+        "u64" | "usize" => serde_json::json!({ "type": "integer", "minimum": 0 }),
+        "i8" => serde_json::json!({ "type": "integer", "minimum": i8::MIN, "maximum": i8::MAX }),
+        "i16" => serde_json::json!({ "type": "integer", "minimum": i16::MIN, "maximum": i16::MAX }),
+        "i32" => serde_json::json!({ "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX }),
+        "i64" | "isize" => serde_json::json!({ "type": "integer" }),
+        "String" => serde_json::json!({ "type": "string" }),
+        // Enum-like and otherwise unrecognized types: degrade to an untyped entry (the
+        // caller still attaches `description`/`default`) rather than failing generation.
+        _ => serde_json::json!({}),
+    }
+}
+
+/// Parses a config option's `default` (rendered via `Debug`, e.g. `"64"`, `"true"` or
+/// `"[\"foo\"]"`) into the JSON value that shape actually represents, so the schema doesn't
+/// advertise a self-contradictory entry like `{"type":"integer","default":"64"}`. Defaults
+/// that don't parse as JSON (e.g. an enum variant's `Debug` output) fall back to a JSON string
+/// of the raw text rather than failing generation.
+fn default_to_json_value(default: &str) -> serde_json::Value {
+    serde_json::from_str(default).unwrap_or_else(|_| serde_json::json!(default))
+}
+
+/// Builds the JSON Schema document for a `clippy-toml.schema.json`, describing a valid
+/// `clippy.toml` as derived from the collected configuration metadata. Editors and language
+/// servers can use it to offer completion, hover docs (from each option's `doc` field) and
+/// validation. Only the schema value is returned; this function does not write the file.
+#[cfg(feature = "internal")]
+pub fn configs_to_json_schema(configs: &[ClippyConfiguration]) -> serde_json::Value {
+    let properties: serde_json::Map<String, serde_json::Value> = configs
+        .iter()
+        .map(|config| {
+            let mut schema = config_type_to_json_schema(config.config_type);
+            if let Some(schema) = schema.as_object_mut() {
+                schema.insert("description".to_string(), serde_json::json!(config.doc));
+                schema.insert("default".to_string(), default_to_json_value(&config.default));
+            }
+            (config.name.clone(), schema)
+        })
+        .collect();
+
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "clippy.toml",
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    })
+}
+
+/// Inverts the collected configuration metadata into a `lint name -> configurations` map, so
+/// a lint's documentation page can list which options tune it. Deprecated configuration
+/// options are excluded, since advertising a removed option on a lint's docs would steer
+/// users towards something they can no longer use.
+#[cfg(feature = "internal")]
+pub fn configs_by_lint(configs: &[ClippyConfiguration]) -> std::collections::HashMap<&str, Vec<&ClippyConfiguration>> {
+    let mut by_lint: std::collections::HashMap<&str, Vec<&ClippyConfiguration>> = std::collections::HashMap::new();
+    for config in configs.iter().filter(|config| config.deprecation_reason.is_none()) {
+        for lint in &config.lints {
+            let lint = lint.split_whitespace().next().unwrap_or(lint);
+            by_lint.entry(lint).or_default().push(config);
+        }
+    }
+    by_lint
+}
+
+/// Renders the "Configuration" section for a single lint's documentation page: one compact
+/// entry per tunable, linking back to its anchor on `lint_configuration.html`.
+#[cfg(feature = "internal")]
+pub fn render_lint_configuration_section(configs: &[&ClippyConfiguration]) -> String {
+    if configs.is_empty() {
+        return String::new();
+    }
+
+    let entries = configs
+        .iter()
+        .map(|config| {
+            format!(
+                "* [`{name}`](https://doc.rust-lang.org/clippy/lint_configuration.html#{name}) (`{ty}`, defaults to `{default}`): {doc}",
+                name = config.name,
+                ty = config.config_type,
+                default = config.default,
+                doc = config.doc.lines().next().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("\n### Configuration\n{entries}\n")
+}
+
 /// This parses the field documentation of the config struct.
 ///
 /// ```rust, ignore
-/// parse_config_field_doc(cx, "Lint: LINT_NAME_1, LINT_NAME_2. Papa penguin, papa penguin")
+/// parse_config_field_doc(" Lint: LINT_NAME_1, LINT_NAME_2.\n\n Papa penguin, papa penguin.")
 /// ```
 ///
 /// Would yield:
 /// ```rust, ignore
-/// Some(["lint_name_1", "lint_name_2"], "Papa penguin, papa penguin")
+/// Some(["lint_name_1", "lint_name_2"], "Papa penguin, papa penguin.")
 /// ```
+///
+/// Only the first line is inspected for the `Lint: ` header and its comma-separated lint
+/// list, so a period inside a code example, URL or abbreviation further down in the body can
+/// no longer be mistaken for the end of the header. The header's terminating `.` is found
+/// with the first line only (not required to be its very last character), so trailing
+/// whitespace after it, or prose that continues on the same line right after the header, are
+/// both still accepted; a header with no `.` at all simply treats
+/// the whole first line as the lint list. Everything else - the rest of the first line plus
+/// every line after the first newline - becomes the documentation body, dedented by the
+/// single leading space every `///` continuation line carries, but otherwise kept verbatim so
+/// fenced code blocks, bullet lists and links survive intact.
 fn parse_config_field_doc(doc_comment: &str) -> Option<(Vec<String>, String)> {
     const DOC_START: &str = " Lint: ";
-    if doc_comment.starts_with(DOC_START)
-        && let Some(split_pos) = doc_comment.find('.')
-    {
-        let mut doc_comment = doc_comment.to_string();
-        let mut documentation = doc_comment.split_off(split_pos);
-
-        // Extract lints
-        doc_comment.make_ascii_lowercase();
-        let lints: Vec<String> = doc_comment
-            .split_off(DOC_START.len())
-            .split(", ")
-            .map(str::to_string)
-            .collect();
-
-        // Format documentation correctly
-        // split off leading `.` from lint name list and indent for correct formatting
-        documentation = documentation.trim_start_matches('.').trim().replace("\n ", "\n    ");
-
-        Some((lints, documentation))
-    } else {
-        None
-    }
+
+    let (first_line, rest) = doc_comment.split_once('\n').unwrap_or((doc_comment, ""));
+    let first_line = first_line.strip_prefix(DOC_START)?;
+
+    let header_end = first_line.find('.').unwrap_or(first_line.len());
+    let (lint_list, trailing) = first_line.split_at(header_end);
+    let trailing = trailing.trim_start_matches('.').trim_start();
+
+    let lints = lint_list
+        .trim()
+        .to_ascii_lowercase()
+        .split(", ")
+        .map(str::to_string)
+        .collect();
+
+    let body = rest
+        .trim_start_matches('\n')
+        .lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let documentation = match (trailing.is_empty(), body.is_empty()) {
+        (true, _) => body,
+        (false, true) => trailing.to_string(),
+        // Keep the blank-line paragraph break between the header's trailing prose and the
+        // body: a single `\n` is only a Markdown soft line break, not a paragraph boundary.
+        (false, false) => format!("{trailing}\n\n{body}"),
+    };
+
+    Some((lints, documentation))
 }
 
 /// Transforms a given `snake_case_string` to a tasty `kebab-case-string`
 fn to_kebab(config_name: &str) -> String {
     config_name.replace('_', "-")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_config_field_doc;
+
+    #[test]
+    fn multi_line_body_is_preserved_verbatim() {
+        let doc = " Lint: FOO, BAR.\n\n Docs with a 3.14 constant, a https://example.com/a.b.c link,\n and a list:\n - one\n - two\n\n     let fenced = \"code block\";";
+        let (lints, body) = parse_config_field_doc(doc).unwrap();
+        assert_eq!(lints, vec!["foo", "bar"]);
+        assert_eq!(
+            body,
+            "Docs with a 3.14 constant, a https://example.com/a.b.c link,\nand a list:\n- one\n- two\n\n    let fenced = \"code block\";"
+        );
+    }
+
+    #[test]
+    fn header_and_prose_may_share_a_single_line() {
+        let (lints, body) = parse_config_field_doc(" Lint: FOO, BAR. Papa penguin, papa penguin").unwrap();
+        assert_eq!(lints, vec!["foo", "bar"]);
+        assert_eq!(body, "Papa penguin, papa penguin");
+    }
+
+    #[test]
+    fn header_prose_and_body_paragraph_stay_separate_paragraphs() {
+        let (lints, body) = parse_config_field_doc(" Lint: FOO. Short summary.\n\n Longer explanation paragraph.").unwrap();
+        assert_eq!(lints, vec!["foo"]);
+        assert_eq!(body, "Short summary.\n\nLonger explanation paragraph.");
+    }
+
+    #[test]
+    fn trailing_whitespace_after_header_period_is_tolerated() {
+        let (lints, body) = parse_config_field_doc(" Lint: FOO. \n\n Body text.").unwrap();
+        assert_eq!(lints, vec!["foo"]);
+        assert_eq!(body, "Body text.");
+    }
+
+    #[test]
+    fn missing_terminal_period_on_header_still_parses() {
+        let (lints, body) = parse_config_field_doc(" Lint: FOO, BAR \n\n Body text.").unwrap();
+        assert_eq!(lints, vec!["foo", "bar"]);
+        assert_eq!(body, "Body text.");
+    }
+
+    #[test]
+    fn missing_lint_header_is_rejected() {
+        assert!(parse_config_field_doc("Not a lint header.\n\nSome text.").is_none());
+    }
+}